@@ -23,7 +23,107 @@
 //! (without any boxing) to overcome rust's recursive type limitations.
 //! In most cases, however, the optimizer should be able to eliminate any dynamic dispatch.
 //!
-//! Unfortunately, mutable recursive closures are not supported.
+//! The sibling macro [`fix_fn_mut`] lets the closure body be an [`FnMut`], so it can
+//! mutate captured state from call to call, including while recursing; unlike [`fix_fn`],
+//! it runs on an explicit trampoline rather than the real call stack, since a single
+//! [`FnMut`] cannot be invoked again while an outer call to it is still running.
+//! [`fix_fn_memo`] instead caches every call (including recursive ones) by its arguments,
+//! turning naively exponential recursive closures into linear ones for free.
+//! [`fix_fn_trampoline`] is [`fix_fn_mut`]'s trampoline without the `FnMut`, for when the
+//! closure only needs to recurse arbitrarily deep, not mutate captured state.
+//!
+//! [`fix_fn`] itself is sugar around [`Fix`], which is the underlying, independently
+//! usable recursive-closure type. Reach for [`Fix`] directly when the recursive closure
+//! needs a name, e.g. because it is stored in a struct field.
+
+/// The type [`fix_fn`]'s expansion is built on (the closure it returns wraps a `Fix`), and
+/// independently usable wherever a recursive closure needs a nameable type, e.g. as the
+/// type of a struct field.
+///
+/// `Args` is the tuple of the closure's (non-recursive) parameters and `Ret` is its
+/// return type. `F` is the body closure, whose first parameter is a `&dyn` reference to
+/// the `Fix` itself, to be used for recursive calls.
+///
+/// Prefer [`fix_fn`] unless the recursive closure's type needs to be named.
+///
+/// # Example
+///
+/// ```
+/// use fix_fn::Fix;
+///
+/// struct Calculator<F: Fn(&dyn fix_fn::HideFn<(u32,), u32>, (u32,)) -> u32> {
+///     fib: Fix<F, (u32,), u32>,
+/// }
+///
+/// let calculator = Calculator {
+///     fib: Fix::new(|fib, (i,): (u32,)| -> u32 {
+///         if i <= 1 {
+///             i
+///         } else {
+///             fib.call((i - 1,)) + fib.call((i - 2,))
+///         }
+///     }),
+/// };
+///
+/// assert_eq!(calculator.fib.call((10,)), 55);
+/// ```
+pub struct Fix<F, Args, Ret>
+where
+    F: Fn(&dyn HideFn<Args, Ret>, Args) -> Ret,
+{
+    f: F,
+    _marker: ::std::marker::PhantomData<(Args, Ret)>,
+}
+
+impl<F, Args, Ret> Fix<F, Args, Ret>
+where
+    F: Fn(&dyn HideFn<Args, Ret>, Args) -> Ret,
+{
+    /// Wraps `f` into a callable, recursive [`Fix`]. `f`'s first parameter is a `&dyn`
+    /// reference to the `Fix` itself, to be used for recursive calls via [`HideFn::call`].
+    pub fn new(f: F) -> Self {
+        Fix {
+            f,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+
+    /// Calls the recursive closure with `args`.
+    #[inline]
+    pub fn call(&self, args: Args) -> Ret {
+        HideFn::call(self, args)
+    }
+}
+
+impl<F, Args, Ret> HideFn<Args, Ret> for Fix<F, Args, Ret>
+where
+    F: Fn(&dyn HideFn<Args, Ret>, Args) -> Ret,
+{
+    #[inline]
+    fn call(&self, args: Args) -> Ret {
+        (self.f)(self, args)
+    }
+}
+
+/// The `dyn`-safe handle recursive closures use to call themselves, without the
+/// otherwise-infinitely-recursive closure type ever having to name itself. [`Fix`] is the
+/// only type implementing it.
+pub trait HideFn<Args, Ret> {
+    /// Calls the recursive closure with `args`.
+    fn call(&self, args: Args) -> Ret;
+}
+
+/// The value a [`fix_fn_mut!`] or [`fix_fn_trampoline!`] body yields at each step: either
+/// the arguments for the next step, or the final result.
+///
+/// `Args` is the tuple of the closure's (non-`rec`) parameters and `Ret` is its return
+/// type, with the same meaning as in [`Fix`].
+pub enum Step<Args, Ret> {
+    /// Continue with another step, using `Args` as the next call's arguments.
+    Recurse(Args),
+    /// Stop and produce this as the final result.
+    Done(Ret),
+}
 
 /// Takes a closure definition where the first parameter will be a [`Fn`] to the closure itself.
 /// Returns a recursive closure with the same signature, except the first parameter will be
@@ -40,11 +140,23 @@
 ///
 /// `move` can be used and has the [usual semantic](https://doc.rust-lang.org/1.18.0/book/first-edition/closures.html#move-closures).
 ///
+/// An optional, turbofish-style generic clause may precede the closure, e.g.
+/// `<T: Clone + PartialOrd>`, together with an optional `where` clause placed after the
+/// return type. Recursion can be factored out of a function that is itself generic into
+/// a closure instead of a free-standing helper function, with or without it. Bounds in
+/// the generic clause and in the `where` clause must be simple trait names (no paths or
+/// generic arguments). The clause is for readability at the call site; the generic type
+/// is already in scope from the enclosing item, and its bounds are enforced by the body
+/// using it, same as they would be without the clause.
+///
+/// Expands to a call to [`Fix::new`]; reach for [`Fix`] directly instead of this macro if
+/// the recursive closure's type needs to be named, e.g. to store it in a struct field.
+///
 /// # Example
 ///
 /// ```
 /// use fix_fn::fix_fn;
-///  
+///
 /// let fib = fix_fn!(|fib, i: u32| -> u32 {
 ///     if i <= 1 {
 ///         i
@@ -57,8 +169,208 @@
 /// // resulting lambda only has the `i: u32` parameter
 /// assert_eq!(fib(7), 13);
 /// ```
+///
+/// A generic example, factoring a binary search out of a generic function:
+///
+/// ```
+/// use fix_fn::fix_fn;
+///
+/// fn first_at_least<T: Clone + PartialOrd>(items: &[T], target: T) -> usize {
+///     let search = fix_fn!(<T: Clone + PartialOrd> |search, lo: usize, hi: usize| -> usize {
+///         if lo >= hi {
+///             lo
+///         } else {
+///             let mid = lo + (hi - lo) / 2;
+///             if items[mid] >= target {
+///                 search(lo, mid)
+///             } else {
+///                 search(mid + 1, hi)
+///             }
+///         }
+///     });
+///     search(0, items.len())
+/// }
+///
+/// let items = vec![1, 3, 5, 7, 9];
+/// assert_eq!(first_at_least(&items, 6), 3);
+/// ```
 #[macro_export]
 macro_rules! fix_fn {
+    (
+        $(< $($gen_name:ident $(: $gen_bound:ident $(+ $gen_bound_rest:ident)*)?),+ $(,)? >)?
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident : $arg_type:ty)* $(,)? |
+            -> $ret_type:ty
+        $(where $($where_ty:ty : $where_bound:ident $(+ $where_bound_rest:ident)*),+ $(,)?)?
+        $body:block
+    ) => {{
+        let inner = $crate::Fix::new(
+            #[inline]
+            $($mov)?
+            |$self_arg, ($($arg_name,)*): ($($arg_type,)*)| -> $ret_type {
+                let $self_arg = |$($arg_name : $arg_type ),*| $self_arg.call(($($arg_name,)*));
+                {
+                    $body
+                }
+            },
+        );
+
+        #[inline]
+        move |$($arg_name : $arg_type),*| -> $ret_type {
+            inner.call(($($arg_name,)*))
+        }
+    }};
+    (
+        $(< $($gen_name:ident $(: $gen_bound:ident $(+ $gen_bound_rest:ident)*)?),+ $(,)? >)?
+        $($mov:ident)? |$($arg_name:ident $(: $arg_type:ty)?),* $(,)?|
+        $body:expr
+    ) => {
+        compile_error!("Closure passed to fix_fn needs return type!");
+    };
+    (
+        $(< $($gen_name:ident $(: $gen_bound:ident $(+ $gen_bound_rest:ident)*)?),+ $(,)? >)?
+        $($mov:ident)? |$self_arg:ident : $self_type:ty $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!(concat!("First parameter ", stringify!($self_arg), " may not have type annotation!"));
+    };
+    (
+        $(< $($gen_name:ident $(: $gen_bound:ident $(+ $gen_bound_rest:ident)*)?),+ $(,)? >)?
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!("All parameters except first need to have an explicit type annotation!");
+    };
+}
+
+/// Like [`fix_fn`], but the closure body may be an [`FnMut`], so it can mutate captured
+/// state from call to call, including while recursing, e.g. a DFS that pushes into a
+/// captured [`Vec`].
+///
+/// A single [`FnMut`] cannot be called again while an outer call to it hasn't returned
+/// yet, so unlike [`fix_fn`], the first parameter, `rec`, does not actually re-enter the
+/// closure. Instead, calling `rec(args)` is shorthand for returning
+/// [`Step::Recurse`]`(args)`, and a driver loop repeatedly calls the closure again with
+/// those arguments, so only one invocation of the body is ever running at a time — no
+/// nested, overlapping calls, and so no borrow conflict. The body's tail expression must
+/// therefore be a [`Step`], not the closure's eventual return type directly: return
+/// [`Step::Done`]`(ret)` once the recursion's base case is reached.
+///
+/// The passed closure needs to have at least one parameter. This
+/// first parameter is the `rec` described above, which requests the next step rather than
+/// calling the closure itself. It must not be annotated with a type.
+///
+/// Additional parameters will be parameters of the resulting closure.
+/// All additional parameters must be annotated with types.
+///
+/// The closure definition needs to have a result-type annotation, naming the type
+/// produced once the trampoline is [`Step::Done`], not the [`Step`] type itself.
+///
+/// `move` can be used and has the [usual semantic](https://doc.rust-lang.org/1.18.0/book/first-edition/closures.html#move-closures).
+///
+/// # Example
+///
+/// ```
+/// use fix_fn::{fix_fn_mut, Step};
+///
+/// let mut visited = Vec::new();
+/// let mut dfs = fix_fn_mut!(|rec, n: u32| -> () {
+///     visited.push(n);
+///     if n > 0 {
+///         rec(n - 1)
+///     } else {
+///         Step::Done(())
+///     }
+/// });
+///
+/// dfs(3);
+/// assert_eq!(visited, vec![3, 2, 1, 0]);
+/// ```
+#[macro_export]
+macro_rules! fix_fn_mut {
+    (
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident : $arg_type:ty)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {{
+        #[inline]
+        fn __fix_fn_mut_step($($arg_name : $arg_type ,)*) -> $crate::Step<($($arg_type,)*), $ret_type> {
+            $crate::Step::Recurse(($($arg_name,)*))
+        }
+
+        let mut body = $($mov)? |$self_arg: fn($($arg_type,)*) -> $crate::Step<($($arg_type,)*), $ret_type>, $($arg_name : $arg_type ,)*| -> $crate::Step<($($arg_type,)*), $ret_type> {
+            $body
+        };
+
+        #[inline]
+        move |$($arg_name : $arg_type),*| -> $ret_type {
+            let mut args = ($($arg_name,)*);
+            loop {
+                let ($($arg_name,)*) = args;
+                match body(__fix_fn_mut_step, $($arg_name,)*) {
+                    $crate::Step::Recurse(next_args) => args = next_args,
+                    $crate::Step::Done(ret) => return ret,
+                }
+            }
+        }
+    }};
+    (
+        $($mov:ident)? |$($arg_name:ident $(: $arg_type:ty)?),* $(,)?|
+        $body:expr
+    ) => {
+        compile_error!("Closure passed to fix_fn_mut needs return type!");
+    };
+    (
+        $($mov:ident)? |$self_arg:ident : $self_type:ty $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!(concat!("First parameter ", stringify!($self_arg), " may not have type annotation!"));
+    };
+    (
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!("All parameters except first need to have an explicit type annotation!");
+    };
+}
+
+/// Like [`fix_fn`], but transparently memoizes the result of every call, keyed by its
+/// arguments, including recursive self-calls.
+///
+/// The passed closure needs to have at least one parameter. This
+/// first parameter can be used to call the closure itself, achieving recursion.
+/// It must not be annotated with a type.
+///
+/// Additional parameters will be parameters of the resulting closure.
+/// All additional parameters must be annotated with types. Together they form the cache
+/// key, so their types must implement [`Clone`], [`Eq`] and [`Hash`](std::hash::Hash).
+/// The return type must implement [`Clone`].
+///
+/// The closure definition needs to have a result-type annotation.
+///
+/// `move` can be used and has the [usual semantic](https://doc.rust-lang.org/1.18.0/book/first-edition/closures.html#move-closures).
+///
+/// # Example
+///
+/// ```
+/// use fix_fn::fix_fn_memo;
+///
+/// // without memoization this would be exponential in `i`
+/// let fib = fix_fn_memo!(|fib, i: u64| -> u64 {
+///     if i <= 1 {
+///         i
+///     } else {
+///         fib(i - 1) + fib(i - 2)
+///     }
+/// });
+///
+/// assert_eq!(fib(50), 12586269025);
+/// ```
+#[macro_export]
+macro_rules! fix_fn_memo {
     (
         $($mov:ident)? |$self_arg:ident $(, $arg_name:ident : $arg_type:ty)* $(,)? |
             -> $ret_type:ty
@@ -68,12 +380,26 @@ macro_rules! fix_fn {
             fn call(&self, $($arg_name : $arg_type ,)*) -> $ret_type;
         }
 
-        struct HideFnImpl<F: Fn(&dyn HideFn, $($arg_type ,)*) -> $ret_type>(F);
+        struct HideFnImpl<F: Fn(&dyn HideFn, $($arg_type ,)*) -> $ret_type>(
+            F,
+            ::std::cell::RefCell<::std::collections::HashMap<($($arg_type ,)*), $ret_type>>,
+        );
 
-        impl<F: Fn(&dyn HideFn, $($arg_type ,)*) -> $ret_type> HideFn for HideFnImpl<F> {
+        impl<F: Fn(&dyn HideFn, $($arg_type ,)*) -> $ret_type> HideFn for HideFnImpl<F>
+        where
+            ($($arg_type ,)*): Clone + Eq + ::std::hash::Hash,
+            $ret_type: Clone,
+        {
             #[inline]
             fn call(&self, $($arg_name : $arg_type ,)*) -> $ret_type {
-                self.0(self, $($arg_name ,)*)
+                let key = ($($arg_name.clone() ,)*);
+                if let Some(cached) = self.1.borrow().get(&key) {
+                    return cached.clone();
+                }
+
+                let result = self.0(self, $($arg_name ,)*);
+                self.1.borrow_mut().insert(key, result.clone());
+                result
             }
         }
 
@@ -85,7 +411,8 @@ macro_rules! fix_fn {
                 {
                     $body
                 }
-            }
+            },
+            ::std::cell::RefCell::new(::std::collections::HashMap::new()),
         );
 
 
@@ -98,7 +425,92 @@ macro_rules! fix_fn {
         $($mov:ident)? |$($arg_name:ident $(: $arg_type:ty)?),* $(,)?|
         $body:expr
     ) => {
-        compile_error!("Closure passed to fix_fn needs return type!");
+        compile_error!("Closure passed to fix_fn_memo needs return type!");
+    };
+    (
+        $($mov:ident)? |$self_arg:ident : $self_type:ty $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!(concat!("First parameter ", stringify!($self_arg), " may not have type annotation!"));
+    };
+    (
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {
+        compile_error!("All parameters except first need to have an explicit type annotation!");
+    };
+}
+
+/// Like [`fix_fn`], but recurses on an explicit, heap-allocated work stack instead of the
+/// real call stack, so it can recurse arbitrarily deep without overflowing the stack.
+///
+/// The passed closure needs to have at least one parameter. This first parameter, `rec`,
+/// is not a callable recursive closure like in [`fix_fn`]: calling it just builds the
+/// value the body must return to request another step, `rec(args)` being shorthand for
+/// [`Step::Recurse`]`(args)`. The body's tail expression must therefore be a [`Step`], not
+/// the closure's eventual return type directly: return [`Step::Done`]`(ret)` once the
+/// recursion's base case is reached.
+///
+/// Additional parameters will be parameters of the resulting closure.
+/// All additional parameters must be annotated with types.
+///
+/// The closure definition needs to have a result-type annotation, naming the type
+/// produced once the trampoline is [`Step::Done`], not the [`Step`] type itself.
+///
+/// `move` can be used and has the [usual semantic](https://doc.rust-lang.org/1.18.0/book/first-edition/closures.html#move-closures).
+///
+/// # Example
+///
+/// ```
+/// use fix_fn::{fix_fn_trampoline, Step};
+///
+/// // sums 1..=n without recursing on the real call stack, so `n` can be huge
+/// let sum_to = fix_fn_trampoline!(|rec, n: u64, acc: u64| -> u64 {
+///     if n == 0 {
+///         Step::Done(acc)
+///     } else {
+///         rec(n - 1, acc + n)
+///     }
+/// });
+///
+/// assert_eq!(sum_to(1_000_000, 0), 500_000_500_000);
+/// ```
+#[macro_export]
+macro_rules! fix_fn_trampoline {
+    (
+        $($mov:ident)? |$self_arg:ident $(, $arg_name:ident : $arg_type:ty)* $(,)? |
+            -> $ret_type:ty
+        $body:block
+    ) => {{
+        #[inline]
+        fn __fix_fn_trampoline_step($($arg_name : $arg_type ,)*) -> $crate::Step<($($arg_type,)*), $ret_type> {
+            $crate::Step::Recurse(($($arg_name,)*))
+        }
+
+        let body = $($mov)? |$self_arg: fn($($arg_type,)*) -> $crate::Step<($($arg_type,)*), $ret_type>, $($arg_name : $arg_type ,)*| -> $crate::Step<($($arg_type,)*), $ret_type> {
+            $body
+        };
+
+        #[inline]
+        move |$($arg_name : $arg_type),*| -> $ret_type {
+            let mut stack = ::std::vec![($($arg_name,)*)];
+            loop {
+                let frame = stack.pop().expect("fix_fn_trampoline: work stack unexpectedly empty");
+                let ($($arg_name,)*) = frame;
+                match body(__fix_fn_trampoline_step, $($arg_name,)*) {
+                    $crate::Step::Recurse(next_args) => stack.push(next_args),
+                    $crate::Step::Done(ret) => return ret,
+                }
+            }
+        }
+    }};
+    (
+        $($mov:ident)? |$($arg_name:ident $(: $arg_type:ty)?),* $(,)?|
+        $body:expr
+    ) => {
+        compile_error!("Closure passed to fix_fn_trampoline needs return type!");
     };
     (
         $($mov:ident)? |$self_arg:ident : $self_type:ty $(, $arg_name:ident $(: $arg_type:ty)?)* $(,)? |
@@ -118,6 +530,7 @@ macro_rules! fix_fn {
 
 #[cfg(test)]
 mod tests {
+    use super::{Fix, HideFn, Step};
     use std::cell::RefCell;
 
     #[test]
@@ -167,4 +580,167 @@ mod tests {
 
         assert_eq!(pow(3, 9), 19683);
     }
+
+    #[test]
+    fn test_fix_stored_in_struct_field() {
+        struct Calculator<F: Fn(&dyn HideFn<(u32,), u32>, (u32,)) -> u32> {
+            fib: Fix<F, (u32,), u32>,
+        }
+
+        let calculator = Calculator {
+            fib: Fix::new(|fib, (i,): (u32,)| -> u32 {
+                if i <= 1 {
+                    i
+                } else {
+                    fib.call((i - 1,)) + fib.call((i - 2,))
+                }
+            }),
+        };
+
+        assert_eq!(calculator.fib.call((10,)), 55);
+    }
+
+    #[test]
+    fn test_generic_parameter() {
+        fn first_at_least<T: Clone + PartialOrd>(items: &[T], target: T) -> usize {
+            let search = fix_fn!(<T: Clone + PartialOrd> |search, lo: usize, hi: usize| -> usize {
+                if lo >= hi {
+                    lo
+                } else {
+                    let mid = lo + (hi - lo) / 2;
+                    if items[mid] >= target {
+                        search(lo, mid)
+                    } else {
+                        search(mid + 1, hi)
+                    }
+                }
+            });
+
+            search(0, items.len())
+        }
+
+        let items = vec![1, 3, 5, 7, 9];
+        assert_eq!(first_at_least(&items, 6), 3);
+        assert_eq!(first_at_least(&items, 1), 0);
+        assert_eq!(first_at_least(&items, 10), 5);
+    }
+
+    #[test]
+    fn test_generic_where_clause() {
+        fn clamp<T>(lo: T, hi: T) -> impl Fn(T) -> T
+        where
+            T: Clone + PartialOrd,
+        {
+            fix_fn!(<T> move |_rec, x: T| -> T
+                where T: Clone + PartialOrd
+            {
+                if x < lo {
+                    lo.clone()
+                } else if x > hi {
+                    hi.clone()
+                } else {
+                    x
+                }
+            })
+        }
+
+        let clamp_0_10 = clamp(0, 10);
+        assert_eq!(clamp_0_10(-5), 0);
+        assert_eq!(clamp_0_10(15), 10);
+        assert_eq!(clamp_0_10(5), 5);
+    }
+
+    #[test]
+    fn test_mut_accumulator() {
+        let mut total = 0u32;
+        let mut accumulate = fix_fn_mut!(|_rec, n: u32| -> u32 {
+            total += n;
+            Step::Done(total)
+        });
+
+        assert_eq!(accumulate(1), 1);
+        assert_eq!(accumulate(2), 3);
+        assert_eq!(accumulate(3), 6);
+    }
+
+    #[test]
+    fn test_mut_dfs_recurses_without_panicking() {
+        let mut visited = Vec::new();
+        let mut dfs = fix_fn_mut!(|rec, n: u32| -> () {
+            visited.push(n);
+            if n > 0 {
+                rec(n - 1)
+            } else {
+                Step::Done(())
+            }
+        });
+
+        dfs(3);
+
+        assert_eq!(visited, vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_memo_fib_is_linear() {
+        let calls = RefCell::new(0u32);
+        let fib = fix_fn_memo!(|fib, i: u64| -> u64 {
+            *calls.borrow_mut() += 1;
+            if i <= 1 {
+                i
+            } else {
+                fib(i - 1) + fib(i - 2)
+            }
+        });
+
+        assert_eq!(fib(50), 12586269025);
+        assert!(*calls.borrow() <= 51);
+    }
+
+    #[test]
+    fn test_memo_two_parameter() {
+        let gcd = fix_fn_memo!(|gcd, a: u64, b: u64| -> u64 {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        });
+
+        assert_eq!(gcd(48, 18), 6);
+    }
+
+    #[test]
+    fn test_trampoline_deep_recursion_does_not_overflow_stack() {
+        let sum_to = fix_fn_trampoline!(|rec, n: u64, acc: u64| -> u64 {
+            if n == 0 {
+                Step::Done(acc)
+            } else {
+                rec(n - 1, acc + n)
+            }
+        });
+
+        assert_eq!(sum_to(1_000_000, 0), 500_000_500_000);
+    }
+
+    #[test]
+    fn test_trampoline_walks_long_list() {
+        enum List {
+            Nil,
+            Cons(u64, Box<List>),
+        }
+
+        let mut list = List::Nil;
+        for i in 0..100_000u64 {
+            list = List::Cons(i, Box::new(list));
+        }
+
+        let sum_list = fix_fn_trampoline!(|rec, list: List, acc: u64| -> u64 {
+            match list {
+                List::Nil => Step::Done(acc),
+                List::Cons(x, rest) => rec(*rest, acc + x),
+            }
+        });
+
+        assert_eq!(sum_list(list, 0), 4_999_950_000);
+    }
 }